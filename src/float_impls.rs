@@ -1,37 +1,103 @@
 use core::{num, intrinsics};
-use core::f32::{NAN, NEG_INFINITY};
+use core::f32::{NAN, NEG_INFINITY, INFINITY};
 
-pub trait FloatImpls {
+// When the `libm` feature is enabled, the transcendental methods below are
+// implemented in pure Rust via the `libm` crate instead of `core::intrinsics`
+// or the C `libm`. The classification, sign, `powi` and `to_degrees` methods
+// go through `core::num::Float`, and `core::intrinsics` is imported, under
+// either setting; both are unstable, so this module needs a nightly toolchain
+// with or without the feature.
+#[cfg(feature = "libm")]
+extern crate libm;
+
+// Trig, hyperbolic and cube-root functions with no corresponding `core::intrinsics` entry
+// point are forwarded to the platform's C `libm`, mirroring how `std::f32`
+// dispatches them through `sys::cmath` when the `libm` feature is off.
+#[cfg(not(feature = "libm"))]
+mod cmath {
+    extern "C" {
+        pub fn tanf(n: f32) -> f32;
+        pub fn asinf(n: f32) -> f32;
+        pub fn acosf(n: f32) -> f32;
+        pub fn atanf(n: f32) -> f32;
+        pub fn atan2f(a: f32, b: f32) -> f32;
+        pub fn sinhf(n: f32) -> f32;
+        pub fn coshf(n: f32) -> f32;
+        pub fn tanhf(n: f32) -> f32;
+        pub fn atanhf(n: f32) -> f32;
+        pub fn cbrtf(n: f32) -> f32;
+
+        pub fn tan(n: f64) -> f64;
+        pub fn asin(n: f64) -> f64;
+        pub fn acos(n: f64) -> f64;
+        pub fn atan(n: f64) -> f64;
+        pub fn atan2(a: f64, b: f64) -> f64;
+        pub fn sinh(n: f64) -> f64;
+        pub fn cosh(n: f64) -> f64;
+        pub fn tanh(n: f64) -> f64;
+        pub fn atanh(n: f64) -> f64;
+        pub fn cbrt(n: f64) -> f64;
+    }
+}
+
+/// Floating-point operations needed by font-rs, implemented for both `f32`
+/// and `f64` so the rasterizer can be made generic over the accumulation
+/// type (mirroring how `std` keeps parallel `f32`/`f64` math modules).
+pub trait Float: Sized {
     fn is_nan(self) -> bool;
     fn is_infinite(self) -> bool;
     fn is_finite(self) -> bool;
     fn is_normal(self) -> bool;
-    fn floor(self) -> f32;
-    fn ceil(self) -> f32;
-    fn round(self) -> f32;
-    fn trunc(self) -> f32;
-    fn fract(self) -> f32;
-    fn signum(self) -> f32;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn signum(self) -> Self;
     fn is_sign_positive(self) -> bool;
     fn is_sign_negative(self) -> bool;
-    fn mul_add(self, a: f32, b: f32) -> f32;
-    fn powi(self, n: i32) -> f32;
-    fn powf(self, n: f32) -> f32;
-    fn sqrt(self) -> f32;
-    fn exp(self) -> f32;
-    fn exp2(self) -> f32;
-    fn ln(self) -> f32;
-    fn log(self, base: f32) -> f32;
-    fn log2(self) -> f32;
-    fn log10(self) -> f32;
-    fn to_degrees(self) -> f32;
-    fn max(self, other: f32) -> f32;
-    fn min(self, other: f32) -> f32;
-    fn asinh(self) -> f32;
-    fn acosh(self) -> f32;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sqrt(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp2(self) -> Self;
+    fn ln(self) -> Self;
+    fn log(self, base: Self) -> Self;
+    fn log2(self) -> Self;
+    fn log10(self) -> Self;
+    fn to_degrees(self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn asinh(self) -> Self;
+    fn acosh(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn asin(self) -> Self;
+    fn acos(self) -> Self;
+    fn atan(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn sinh(self) -> Self;
+    fn cosh(self) -> Self;
+    fn tanh(self) -> Self;
+    fn atanh(self) -> Self;
+    fn hypot(self, other: Self) -> Self;
+    fn cbrt(self) -> Self;
+    fn erf(self) -> Self;
+    fn erfc(self) -> Self;
+    fn abs(self) -> Self;
+    fn recip(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+    fn clamp(self, min: Self, max: Self) -> Self;
 }
 
-impl FloatImpls for f32 {
+/// Alias kept for existing call sites written against the original,
+/// `f32`-only name of this trait.
+pub use self::Float as FloatImpls;
+
+impl Float for f32 {
     /// Returns `true` if this value is `NaN` and false otherwise.
     ///
     /// ```
@@ -128,23 +194,28 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn floor(self) -> f32 {
-        // On MSVC LLVM will lower many math intrinsics to a call to the
-        // corresponding function. On MSVC, however, many of these functions
-        // aren't actually available as symbols to call, but rather they are all
-        // `static inline` functions in header files. This means that from a C
-        // perspective it's "compatible", but not so much from an ABI
-        // perspective (which we're worried about).
-        //
-        // The inline header functions always just cast to a f64 and do their
-        // operation, so we do that here as well, but only for MSVC targets.
-        //
-        // Note that there are many MSVC-specific float operations which
-        // redirect to this comment, so `floorf` is just one case of a missing
-        // function on MSVC, but there are many others elsewhere.
-        #[cfg(target_env = "msvc")]
-        return (self as f64).floor() as f32;
-        #[cfg(not(target_env = "msvc"))]
-        return unsafe { intrinsics::floorf32(self) };
+        #[cfg(feature = "libm")]
+        return libm::floorf(self);
+        #[cfg(not(feature = "libm"))]
+        {
+            // On MSVC LLVM will lower many math intrinsics to a call to the
+            // corresponding function. On MSVC, however, many of these functions
+            // aren't actually available as symbols to call, but rather they are all
+            // `static inline` functions in header files. This means that from a C
+            // perspective it's "compatible", but not so much from an ABI
+            // perspective (which we're worried about).
+            //
+            // The inline header functions always just cast to a f64 and do their
+            // operation, so we do that here as well, but only for MSVC targets.
+            //
+            // Note that there are many MSVC-specific float operations which
+            // redirect to this comment, so `floorf` is just one case of a missing
+            // function on MSVC, but there are many others elsewhere.
+            #[cfg(target_env = "msvc")]
+            return (self as f64).floor() as f32;
+            #[cfg(not(target_env = "msvc"))]
+            return unsafe { intrinsics::floorf32(self) };
+        }
     }
 
     /// Returns the smallest integer greater than or equal to a number.
@@ -158,11 +229,16 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn ceil(self) -> f32 {
-        // see notes above in `floor`
-        #[cfg(target_env = "msvc")]
-        return (self as f64).ceil() as f32;
-        #[cfg(not(target_env = "msvc"))]
-        return unsafe { intrinsics::ceilf32(self) };
+        #[cfg(feature = "libm")]
+        return libm::ceilf(self);
+        #[cfg(not(feature = "libm"))]
+        {
+            // see notes above in `floor`
+            #[cfg(target_env = "msvc")]
+            return (self as f64).ceil() as f32;
+            #[cfg(not(target_env = "msvc"))]
+            return unsafe { intrinsics::ceilf32(self) };
+        }
     }
 
     /// Returns the nearest integer to a number. Round half-way cases away from
@@ -177,7 +253,10 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn round(self) -> f32 {
-        unsafe { intrinsics::roundf32(self) }
+        #[cfg(feature = "libm")]
+        return libm::roundf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::roundf32(self) };
     }
 
     /// Returns the integer part of a number.
@@ -191,7 +270,10 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn trunc(self) -> f32 {
-        unsafe { intrinsics::truncf32(self) }
+        #[cfg(feature = "libm")]
+        return libm::truncf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::truncf32(self) };
     }
 
     /// Returns the fractional part of a number.
@@ -291,7 +373,10 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn mul_add(self, a: f32, b: f32) -> f32 {
-        unsafe { intrinsics::fmaf32(self, a, b) }
+        #[cfg(feature = "libm")]
+        return libm::fmaf(self, a, b);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::fmaf32(self, a, b) };
     }
 
     /// Raises a number to an integer power.
@@ -323,11 +408,16 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn powf(self, n: f32) -> f32 {
-        // see notes above in `floor`
-        #[cfg(target_env = "msvc")]
-        return (self as f64).powf(n as f64) as f32;
-        #[cfg(not(target_env = "msvc"))]
-        return unsafe { intrinsics::powf32(self, n) };
+        #[cfg(feature = "libm")]
+        return libm::powf(self, n);
+        #[cfg(not(feature = "libm"))]
+        {
+            // see notes above in `floor`
+            #[cfg(target_env = "msvc")]
+            return (self as f64).powf(n as f64) as f32;
+            #[cfg(not(target_env = "msvc"))]
+            return unsafe { intrinsics::powf32(self, n) };
+        }
     }
 
     /// Takes the square root of a number.
@@ -350,7 +440,10 @@ impl FloatImpls for f32 {
         if self < 0.0 {
             NAN
         } else {
-            unsafe { intrinsics::sqrtf32(self) }
+            #[cfg(feature = "libm")]
+            return libm::sqrtf(self);
+            #[cfg(not(feature = "libm"))]
+            return unsafe { intrinsics::sqrtf32(self) };
         }
     }
 
@@ -370,11 +463,16 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn exp(self) -> f32 {
-        // see notes above in `floor`
-        #[cfg(target_env = "msvc")]
-        return (self as f64).exp() as f32;
-        #[cfg(not(target_env = "msvc"))]
-        return unsafe { intrinsics::expf32(self) };
+        #[cfg(feature = "libm")]
+        return libm::expf(self);
+        #[cfg(not(feature = "libm"))]
+        {
+            // see notes above in `floor`
+            #[cfg(target_env = "msvc")]
+            return (self as f64).exp() as f32;
+            #[cfg(not(target_env = "msvc"))]
+            return unsafe { intrinsics::expf32(self) };
+        }
     }
 
     /// Returns `2^(self)`.
@@ -391,7 +489,10 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn exp2(self) -> f32 {
-        unsafe { intrinsics::exp2f32(self) }
+        #[cfg(feature = "libm")]
+        return libm::exp2f(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::exp2f32(self) };
     }
 
     /// Returns the natural logarithm of the number.
@@ -410,11 +511,16 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn ln(self) -> f32 {
-        // see notes above in `floor`
-        #[cfg(target_env = "msvc")]
-        return (self as f64).ln() as f32;
-        #[cfg(not(target_env = "msvc"))]
-        return unsafe { intrinsics::logf32(self) };
+        #[cfg(feature = "libm")]
+        return libm::logf(self);
+        #[cfg(not(feature = "libm"))]
+        {
+            // see notes above in `floor`
+            #[cfg(target_env = "msvc")]
+            return (self as f64).ln() as f32;
+            #[cfg(not(target_env = "msvc"))]
+            return unsafe { intrinsics::logf32(self) };
+        }
     }
 
     /// Returns the logarithm of the number with respect to an arbitrary base.
@@ -453,10 +559,15 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn log2(self) -> f32 {
-        #[cfg(target_os = "android")]
-        return ::sys::android::log2f32(self);
-        #[cfg(not(target_os = "android"))]
-        return unsafe { intrinsics::log2f32(self) };
+        #[cfg(feature = "libm")]
+        return libm::log2f(self);
+        #[cfg(not(feature = "libm"))]
+        {
+            #[cfg(target_os = "android")]
+            return ::sys::android::log2f32(self);
+            #[cfg(not(target_os = "android"))]
+            return unsafe { intrinsics::log2f32(self) };
+        }
     }
 
     /// Returns the base 10 logarithm of the number.
@@ -473,11 +584,16 @@ impl FloatImpls for f32 {
     /// ```
     #[inline]
     fn log10(self) -> f32 {
-        // see notes above in `floor`
-        #[cfg(target_env = "msvc")]
-        return (self as f64).log10() as f32;
-        #[cfg(not(target_env = "msvc"))]
-        return unsafe { intrinsics::log10f32(self) };
+        #[cfg(feature = "libm")]
+        return libm::log10f(self);
+        #[cfg(not(feature = "libm"))]
+        {
+            // see notes above in `floor`
+            #[cfg(target_env = "msvc")]
+            return (self as f64).log10() as f32;
+            #[cfg(not(target_env = "msvc"))]
+            return unsafe { intrinsics::log10f32(self) };
+        }
     }
 
     /// Converts radians to degrees.
@@ -566,4 +682,1490 @@ impl FloatImpls for f32 {
             x => (x + ((x * x) - 1.0).sqrt()).ln(),
         }
     }
+
+    /// Computes the sine of a number (in radians).
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let x = f32::consts::FRAC_PI_2;
+    ///
+    /// let abs_difference = (x.sin() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn sin(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::sinf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::sinf32(self) };
+    }
+
+    /// Computes the cosine of a number (in radians).
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let x = 2.0 * f32::consts::PI;
+    ///
+    /// let abs_difference = (x.cos() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn cos(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::cosf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::cosf32(self) };
+    }
+
+    /// Computes the tangent of a number (in radians).
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let x = f32::consts::FRAC_PI_4;
+    /// let abs_difference = (x.tan() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn tan(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::tanf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::tanf(self) };
+    }
+
+    /// Computes the arcsine of a number. Return value is in radians in
+    /// the range [-pi/2, pi/2] or NaN if the number is outside the range
+    /// [-1, 1].
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let f = f32::consts::FRAC_PI_2;
+    ///
+    /// // asin(sin(pi/2))
+    /// let abs_difference = (f.sin().asin() - f32::consts::FRAC_PI_2).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn asin(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::asinf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::asinf(self) };
+    }
+
+    /// Computes the arccosine of a number. Return value is in radians in
+    /// the range [0, pi] or NaN if the number is outside the range
+    /// [-1, 1].
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let f = f32::consts::FRAC_PI_4;
+    ///
+    /// // acos(cos(pi/4))
+    /// let abs_difference = (f.cos().acos() - f32::consts::FRAC_PI_4).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn acos(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::acosf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::acosf(self) };
+    }
+
+    /// Computes the arctangent of a number. Return value is in radians in the
+    /// range [-pi/2, pi/2];
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let f = 1.0f32;
+    ///
+    /// // atan(tan(1))
+    /// let abs_difference = (f.tan().atan() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn atan(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::atanf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::atanf(self) };
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`)
+    /// in radians.
+    ///
+    /// * `x = 0`, `y = 0`: `0`
+    /// * `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
+    /// * `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
+    /// * `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let pi = f32::consts::PI;
+    /// // Positive angles measured counter-clockwise
+    /// // from positive x axis
+    /// // -pi/4 radians (45 deg clockwise)
+    /// let x1 = 3.0f32;
+    /// let y1 = -3.0f32;
+    ///
+    /// let abs_difference_1 = (y1.atan2(x1) - (-pi/4.0)).abs();
+    ///
+    /// assert!(abs_difference_1 <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn atan2(self, other: f32) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::atan2f(self, other);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::atan2f(self, other) };
+    }
+
+    /// Simultaneously computes the sine and cosine of the number, `x`. Returns
+    /// `(sin(x), cos(x))`.
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let x = f32::consts::FRAC_PI_4;
+    /// let f = x.sin_cos();
+    ///
+    /// let abs_difference_0 = (f.0 - x.sin()).abs();
+    /// let abs_difference_1 = (f.1 - x.cos()).abs();
+    ///
+    /// assert!(abs_difference_0 <= f32::EPSILON);
+    /// assert!(abs_difference_1 <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn sin_cos(self) -> (f32, f32) {
+        (self.sin(), self.cos())
+    }
+
+    /// Hyperbolic sine function.
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let e = f32::consts::E;
+    /// let x = 1.0f32;
+    ///
+    /// let f = x.sinh();
+    /// // Solving sinh() at 1 gives `(e^2-1)/(2e)`
+    /// let g = (e * e - 1.0) / (2.0 * e);
+    /// let abs_difference = (f - g).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn sinh(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::sinhf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::sinhf(self) };
+    }
+
+    /// Hyperbolic cosine function.
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let e = f32::consts::E;
+    /// let x = 1.0f32;
+    /// let f = x.cosh();
+    /// // Solving cosh() at 1 gives this result
+    /// let g = (e * e + 1.0) / (2.0 * e);
+    /// let abs_difference = (f - g).abs();
+    ///
+    /// // Same result
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn cosh(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::coshf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::coshf(self) };
+    }
+
+    /// Hyperbolic tangent function.
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let e = f32::consts::E;
+    /// let x = 1.0f32;
+    ///
+    /// let f = x.tanh();
+    /// // Solving tanh() at 1 gives `(1 - e^(-2))/(1 + e^(-2))`
+    /// let g = (1.0 - e.powi(-2)) / (1.0 + e.powi(-2));
+    /// let abs_difference = (f - g).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn tanh(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::tanhf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::tanhf(self) };
+    }
+
+    /// Inverse hyperbolic tangent function.
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let e = f32::consts::E;
+    /// let f = e.tanh().atanh();
+    ///
+    /// let abs_difference = (f - e).abs();
+    ///
+    /// assert!(abs_difference <= 1.0e-5);
+    /// ```
+    #[inline]
+    fn atanh(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::atanhf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::atanhf(self) };
+    }
+
+    /// Calculates the length of the hypotenuse of a right-angle triangle
+    /// given legs of length `self` and `other`, avoiding the overflow and
+    /// underflow that the naive `(self * self + other * other).sqrt()` is
+    /// prone to for very large or very small inputs.
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let x = 2.0f32;
+    /// let y = 3.0f32;
+    ///
+    /// // sqrt(x^2 + y^2)
+    /// let abs_difference = (x.hypot(y) - (x.powi(2) + y.powi(2)).sqrt()).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn hypot(self, other: f32) -> f32 {
+        if self.is_infinite() || other.is_infinite() {
+            return INFINITY;
+        }
+        if self.is_nan() || other.is_nan() {
+            return NAN;
+        }
+        let a = self.abs();
+        let b = other.abs();
+        let hi = a.max(b);
+        let lo = a.min(b);
+        if hi == 0.0 {
+            0.0
+        } else {
+            hi * (1.0 + (lo / hi) * (lo / hi)).sqrt()
+        }
+    }
+
+    /// Returns the cube root of a number, preserving sign
+    /// (`x.cbrt() == -(-x).cbrt()`).
+    ///
+    /// ```
+    /// use std::f32;
+    ///
+    /// let x = 8.0f32;
+    ///
+    /// // x^(1/3) - 2 == 0
+    /// let abs_difference = (x.cbrt() - 2.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// assert_eq!((-x).cbrt(), -x.cbrt());
+    /// ```
+    #[inline]
+    fn cbrt(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::cbrtf(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::cbrtf(self) };
+    }
+
+    /// Computes the error function of a number:
+    /// `erf(x) = (2/sqrt(pi)) * integral from 0 to x of e^(-t^2) dt`.
+    ///
+    /// ```
+    /// use std::f32;
+    /// use font_rs::float_impls::Float;
+    ///
+    /// // Qualified, since `f32` also has its own (unstable) inherent `erf`.
+    /// let abs_difference = (Float::erf(0.0f32) - 0.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn erf(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::erff(self);
+        #[cfg(not(feature = "libm"))]
+        {
+            // Abramowitz & Stegun 7.1.26, accurate to about 1.5e-7.
+            let sign = self.signum();
+            let x = self.abs();
+            let t = 1.0 / (1.0 + 0.3275911 * x);
+            let poly = (((((1.061405429 * t - 1.453152027) * t) + 1.421413741) * t
+                - 0.284496736) * t + 0.254829592) * t;
+            sign * (1.0 - poly * (-x * x).exp())
+        }
+    }
+
+    /// Computes the complementary error function of a number: `1 - erf(x)`.
+    ///
+    /// ```
+    /// use std::f32;
+    /// use font_rs::float_impls::Float;
+    ///
+    /// // Qualified, since `f32` also has its own (unstable) inherent `erfc`.
+    /// let abs_difference = (Float::erfc(0.0f32) - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn erfc(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::erfcf(self);
+        #[cfg(not(feature = "libm"))]
+        return 1.0 - self.erf();
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// ```
+    /// let x = 3.5_f32;
+    /// let y = -3.5_f32;
+    ///
+    /// assert_eq!(x.abs(), x);
+    /// assert_eq!(y.abs(), x);
+    /// ```
+    #[inline]
+    fn abs(self) -> f32 {
+        #[cfg(feature = "libm")]
+        return libm::fabsf(self);
+        #[cfg(not(feature = "libm"))]
+        return f32::from_bits(self.to_bits() & 0x7fff_ffff);
+    }
+
+    /// Takes the reciprocal (inverse) of a number, `1/x`.
+    ///
+    /// ```
+    /// let x = 2.0_f32;
+    /// let abs_difference = (x.recip() - (1.0 / x)).abs();
+    ///
+    /// assert!(abs_difference <= f32::EPSILON);
+    /// ```
+    #[inline]
+    fn recip(self) -> f32 {
+        1.0 / self
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of
+    /// `sign`, works correctly for `+-0.0` and `NaN`.
+    ///
+    /// ```
+    /// let f = 3.5_f32;
+    ///
+    /// assert_eq!(f.copysign(0.42), 3.5);
+    /// assert_eq!(f.copysign(-0.42), -3.5);
+    /// assert_eq!((-f).copysign(0.42), 3.5);
+    /// assert_eq!((-f).copysign(-0.42), -3.5);
+    ///
+    /// assert!(f32::NAN.copysign(1.0).is_nan());
+    /// ```
+    #[inline]
+    fn copysign(self, sign: f32) -> f32 {
+        f32::from_bits((self.to_bits() & 0x7fff_ffff) | (sign.to_bits() & 0x8000_0000))
+    }
+
+    /// Restricts `self` to the interval `[min, max]`.
+    ///
+    /// Returns `min` if `self` is less than `min`, `max` if `self` is
+    /// greater than `max`, and otherwise returns `self`, propagating `NaN`.
+    ///
+    /// Panics (in debug builds) if `min > max`.
+    ///
+    /// ```
+    /// assert!((-3.0f32).clamp(-2.0, 1.0) == -2.0);
+    /// assert!((0.0f32).clamp(-2.0, 1.0) == 0.0);
+    /// assert!((2.0f32).clamp(-2.0, 1.0) == 1.0);
+    /// ```
+    #[inline]
+    fn clamp(self, min: f32, max: f32) -> f32 {
+        debug_assert!(min <= max);
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+impl Float for f64 {
+    /// Returns `true` if this value is `NaN` and false otherwise.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let nan = f64::NAN;
+    /// let f = 7.0_f64;
+    ///
+    /// assert!(nan.is_nan());
+    /// assert!(!f.is_nan());
+    /// ```
+    #[inline]
+    fn is_nan(self) -> bool {
+        num::Float::is_nan(self)
+    }
+
+    /// Returns `true` if this value is positive infinity or negative infinity and
+    /// false otherwise.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let f = 7.0f64;
+    /// let inf = f64::INFINITY;
+    /// let neg_inf = f64::NEG_INFINITY;
+    /// let nan = f64::NAN;
+    ///
+    /// assert!(!f.is_infinite());
+    /// assert!(!nan.is_infinite());
+    ///
+    /// assert!(inf.is_infinite());
+    /// assert!(neg_inf.is_infinite());
+    /// ```
+    #[inline]
+    fn is_infinite(self) -> bool {
+        num::Float::is_infinite(self)
+    }
+
+    /// Returns `true` if this number is neither infinite nor `NaN`.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let f = 7.0f64;
+    /// let inf = f64::INFINITY;
+    /// let neg_inf = f64::NEG_INFINITY;
+    /// let nan = f64::NAN;
+    ///
+    /// assert!(f.is_finite());
+    ///
+    /// assert!(!nan.is_finite());
+    /// assert!(!inf.is_finite());
+    /// assert!(!neg_inf.is_finite());
+    /// ```
+    #[inline]
+    fn is_finite(self) -> bool {
+        num::Float::is_finite(self)
+    }
+
+    /// Returns `true` if the number is neither zero, infinite,
+    /// [subnormal][subnormal], or `NaN`.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let min = f64::MIN_POSITIVE;
+    /// let max = f64::MAX;
+    /// let lower_than_min = 1.0e-308_f64;
+    /// let zero = 0.0_f64;
+    ///
+    /// assert!(min.is_normal());
+    /// assert!(max.is_normal());
+    ///
+    /// assert!(!zero.is_normal());
+    /// assert!(!f64::NAN.is_normal());
+    /// assert!(!f64::INFINITY.is_normal());
+    /// // Values between `0` and `min` are Subnormal.
+    /// assert!(!lower_than_min.is_normal());
+    /// ```
+    /// [subnormal]: https://en.wikipedia.org/wiki/Denormal_number
+    #[inline]
+    fn is_normal(self) -> bool {
+        num::Float::is_normal(self)
+    }
+
+    /// Returns the largest integer less than or equal to a number.
+    ///
+    /// ```
+    /// let f = 3.99_f64;
+    /// let g = 3.0_f64;
+    ///
+    /// assert_eq!(f.floor(), 3.0);
+    /// assert_eq!(g.floor(), 3.0);
+    /// ```
+    #[inline]
+    fn floor(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::floor(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::floorf64(self) };
+    }
+
+    /// Returns the smallest integer greater than or equal to a number.
+    ///
+    /// ```
+    /// let f = 3.01_f64;
+    /// let g = 4.0_f64;
+    ///
+    /// assert_eq!(f.ceil(), 4.0);
+    /// assert_eq!(g.ceil(), 4.0);
+    /// ```
+    #[inline]
+    fn ceil(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::ceil(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::ceilf64(self) };
+    }
+
+    /// Returns the nearest integer to a number. Round half-way cases away from
+    /// `0.0`.
+    ///
+    /// ```
+    /// let f = 3.3_f64;
+    /// let g = -3.3_f64;
+    ///
+    /// assert_eq!(f.round(), 3.0);
+    /// assert_eq!(g.round(), -3.0);
+    /// ```
+    #[inline]
+    fn round(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::round(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::roundf64(self) };
+    }
+
+    /// Returns the integer part of a number.
+    ///
+    /// ```
+    /// let f = 3.3_f64;
+    /// let g = -3.7_f64;
+    ///
+    /// assert_eq!(f.trunc(), 3.0);
+    /// assert_eq!(g.trunc(), -3.0);
+    /// ```
+    #[inline]
+    fn trunc(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::trunc(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::truncf64(self) };
+    }
+
+    /// Returns the fractional part of a number.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = 3.5_f64;
+    /// let y = -3.5_f64;
+    /// let abs_difference_x = (x.fract() - 0.5).abs();
+    /// let abs_difference_y = (y.fract() - (-0.5)).abs();
+    ///
+    /// assert!(abs_difference_x <= f64::EPSILON);
+    /// assert!(abs_difference_y <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn fract(self) -> f64 {
+        self - self.trunc()
+    }
+
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// - `1.0` if the number is positive, `+0.0` or `INFINITY`
+    /// - `-1.0` if the number is negative, `-0.0` or `NEG_INFINITY`
+    /// - `NAN` if the number is `NAN`
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let f = 3.5_f64;
+    ///
+    /// assert_eq!(f.signum(), 1.0);
+    /// assert_eq!(f64::NEG_INFINITY.signum(), -1.0);
+    ///
+    /// assert!(f64::NAN.signum().is_nan());
+    /// ```
+    #[inline]
+    fn signum(self) -> f64 {
+        num::Float::signum(self)
+    }
+
+    /// Returns `true` if `self`'s sign bit is positive, including
+    /// `+0.0` and `INFINITY`.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let nan = f64::NAN;
+    /// let f = 7.0_f64;
+    /// let g = -7.0_f64;
+    ///
+    /// assert!(f.is_sign_positive());
+    /// assert!(!g.is_sign_positive());
+    /// // The sign bit of a NaN is unspecified, but it is always one or the other.
+    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// ```
+    #[inline]
+    fn is_sign_positive(self) -> bool {
+        num::Float::is_sign_positive(self)
+    }
+
+    /// Returns `true` if `self`'s sign is negative, including `-0.0`
+    /// and `NEG_INFINITY`.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let nan = f64::NAN;
+    /// let f = 7.0f64;
+    /// let g = -7.0f64;
+    ///
+    /// assert!(!f.is_sign_negative());
+    /// assert!(g.is_sign_negative());
+    /// // The sign bit of a NaN is unspecified, but it is always one or the other.
+    /// assert!(nan.is_sign_positive() != nan.is_sign_negative());
+    /// ```
+    #[inline]
+    fn is_sign_negative(self) -> bool {
+        num::Float::is_sign_negative(self)
+    }
+
+    /// Fused multiply-add. Computes `(self * a) + b` with only one rounding
+    /// error. This produces a more accurate result with better performance than
+    /// a separate multiplication operation followed by an add.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let m = 10.0_f64;
+    /// let x = 4.0_f64;
+    /// let b = 60.0_f64;
+    ///
+    /// // 100.0
+    /// let abs_difference = (m.mul_add(x, b) - (m*x + b)).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn mul_add(self, a: f64, b: f64) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::fma(self, a, b);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::fmaf64(self, a, b) };
+    }
+
+    /// Raises a number to an integer power.
+    ///
+    /// Using this function is generally faster than using `powf`
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = 2.0_f64;
+    /// let abs_difference = (x.powi(2) - x*x).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn powi(self, n: i32) -> f64 {
+        num::Float::powi(self, n)
+    }
+
+    /// Raises a number to a floating point power.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = 2.0_f64;
+    /// let abs_difference = (x.powf(2.0) - x*x).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn powf(self, n: f64) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::pow(self, n);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::powf64(self, n) };
+    }
+
+    /// Takes the square root of a number.
+    ///
+    /// Returns NaN if `self` is a negative number.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let positive = 4.0_f64;
+    /// let negative = -4.0_f64;
+    ///
+    /// let abs_difference = (positive.sqrt() - 2.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// assert!(negative.sqrt().is_nan());
+    /// ```
+    #[inline]
+    fn sqrt(self) -> f64 {
+        if self < 0.0 {
+            ::core::f64::NAN
+        } else {
+            #[cfg(feature = "libm")]
+            return libm::sqrt(self);
+            #[cfg(not(feature = "libm"))]
+            return unsafe { intrinsics::sqrtf64(self) };
+        }
+    }
+
+    /// Returns `e^(self)`, (the exponential function).
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let one = 1.0f64;
+    /// // e^1
+    /// let e = one.exp();
+    ///
+    /// // ln(e) - 1 == 0
+    /// let abs_difference = (e.ln() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn exp(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::exp(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::expf64(self) };
+    }
+
+    /// Returns `2^(self)`.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let f = 2.0f64;
+    ///
+    /// // 2^2 - 4 == 0
+    /// let abs_difference = (f.exp2() - 4.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn exp2(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::exp2(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::exp2f64(self) };
+    }
+
+    /// Returns the natural logarithm of the number.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let one = 1.0f64;
+    /// // e^1
+    /// let e = one.exp();
+    ///
+    /// // ln(e) - 1 == 0
+    /// let abs_difference = (e.ln() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn ln(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::log(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::logf64(self) };
+    }
+
+    /// Returns the logarithm of the number with respect to an arbitrary base.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let ten = 10.0f64;
+    /// let two = 2.0f64;
+    ///
+    /// // log10(10) - 1 == 0
+    /// let abs_difference_10 = (ten.log(10.0) - 1.0).abs();
+    ///
+    /// // log2(2) - 1 == 0
+    /// let abs_difference_2 = (two.log(2.0) - 1.0).abs();
+    ///
+    /// assert!(abs_difference_10 <= f64::EPSILON);
+    /// assert!(abs_difference_2 <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn log(self, base: f64) -> f64 {
+        self.ln() / base.ln()
+    }
+
+    /// Returns the base 2 logarithm of the number.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let two = 2.0f64;
+    ///
+    /// // log2(2) - 1 == 0
+    /// let abs_difference = (two.log2() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn log2(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::log2(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::log2f64(self) };
+    }
+
+    /// Returns the base 10 logarithm of the number.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let ten = 10.0f64;
+    ///
+    /// // log10(10) - 1 == 0
+    /// let abs_difference = (ten.log10() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn log10(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::log10(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::log10f64(self) };
+    }
+
+    /// Converts radians to degrees.
+    ///
+    /// ```
+    /// use std::f64::{self, consts};
+    ///
+    /// let angle = consts::PI;
+    ///
+    /// let abs_difference = (angle.to_degrees() - 180.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn to_degrees(self) -> f64 {
+        num::Float::to_degrees(self)
+    }
+
+    /// Returns the maximum of the two numbers.
+    ///
+    /// ```
+    /// let x = 1.0f64;
+    /// let y = 2.0f64;
+    ///
+    /// assert_eq!(x.max(y), y);
+    /// ```
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    #[inline]
+    fn max(self, other: f64) -> f64 {
+        if self > other { self } else { other }
+    }
+
+    /// Returns the minimum of the two numbers.
+    ///
+    /// ```
+    /// let x = 1.0f64;
+    /// let y = 2.0f64;
+    ///
+    /// assert_eq!(x.min(y), x);
+    /// ```
+    ///
+    /// If one of the arguments is NaN, then the other argument is returned.
+    #[inline]
+    fn min(self, other: f64) -> f64 {
+        if self < other { self } else { other }
+    }
+
+    /// Inverse hyperbolic sine function.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = 1.0f64;
+    /// let f = x.sinh().asinh();
+    ///
+    /// let abs_difference = (f - x).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn asinh(self) -> f64 {
+        if self == ::core::f64::NEG_INFINITY {
+            ::core::f64::NEG_INFINITY
+        } else {
+            (self + ((self * self) + 1.0).sqrt()).ln()
+        }
+    }
+
+    /// Inverse hyperbolic cosine function.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = 1.0f64;
+    /// let f = x.cosh().acosh();
+    ///
+    /// let abs_difference = (f - x).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn acosh(self) -> f64 {
+        match self {
+            x if x < 1.0 => ::core::f64::NAN,
+            x => (x + ((x * x) - 1.0).sqrt()).ln(),
+        }
+    }
+
+    /// Computes the sine of a number (in radians).
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = f64::consts::FRAC_PI_2;
+    ///
+    /// let abs_difference = (x.sin() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn sin(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::sin(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::sinf64(self) };
+    }
+
+    /// Computes the cosine of a number (in radians).
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = 2.0 * f64::consts::PI;
+    ///
+    /// let abs_difference = (x.cos() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn cos(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::cos(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { intrinsics::cosf64(self) };
+    }
+
+    /// Computes the tangent of a number (in radians).
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = f64::consts::FRAC_PI_4;
+    /// let abs_difference = (x.tan() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn tan(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::tan(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::tan(self) };
+    }
+
+    /// Computes the arcsine of a number. Return value is in radians in
+    /// the range [-pi/2, pi/2] or NaN if the number is outside the range
+    /// [-1, 1].
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let f = f64::consts::FRAC_PI_2;
+    ///
+    /// // asin(sin(pi/2))
+    /// let abs_difference = (f.sin().asin() - f64::consts::FRAC_PI_2).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn asin(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::asin(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::asin(self) };
+    }
+
+    /// Computes the arccosine of a number. Return value is in radians in
+    /// the range [0, pi] or NaN if the number is outside the range
+    /// [-1, 1].
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let f = f64::consts::FRAC_PI_4;
+    ///
+    /// // acos(cos(pi/4))
+    /// let abs_difference = (f.cos().acos() - f64::consts::FRAC_PI_4).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn acos(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::acos(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::acos(self) };
+    }
+
+    /// Computes the arctangent of a number. Return value is in radians in the
+    /// range [-pi/2, pi/2];
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let f = 1.0f64;
+    ///
+    /// // atan(tan(1))
+    /// let abs_difference = (f.tan().atan() - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn atan(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::atan(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::atan(self) };
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`)
+    /// in radians.
+    ///
+    /// * `x = 0`, `y = 0`: `0`
+    /// * `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
+    /// * `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
+    /// * `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let pi = f64::consts::PI;
+    /// // Positive angles measured counter-clockwise
+    /// // from positive x axis
+    /// // -pi/4 radians (45 deg clockwise)
+    /// let x1 = 3.0f64;
+    /// let y1 = -3.0f64;
+    ///
+    /// let abs_difference_1 = (y1.atan2(x1) - (-pi/4.0)).abs();
+    ///
+    /// assert!(abs_difference_1 <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn atan2(self, other: f64) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::atan2(self, other);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::atan2(self, other) };
+    }
+
+    /// Simultaneously computes the sine and cosine of the number, `x`. Returns
+    /// `(sin(x), cos(x))`.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = f64::consts::FRAC_PI_4;
+    /// let f = x.sin_cos();
+    ///
+    /// let abs_difference_0 = (f.0 - x.sin()).abs();
+    /// let abs_difference_1 = (f.1 - x.cos()).abs();
+    ///
+    /// assert!(abs_difference_0 <= f64::EPSILON);
+    /// assert!(abs_difference_1 <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn sin_cos(self) -> (f64, f64) {
+        (self.sin(), self.cos())
+    }
+
+    /// Hyperbolic sine function.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let e = f64::consts::E;
+    /// let x = 1.0f64;
+    ///
+    /// let f = x.sinh();
+    /// // Solving sinh() at 1 gives `(e^2-1)/(2e)`
+    /// let g = (e * e - 1.0) / (2.0 * e);
+    /// let abs_difference = (f - g).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn sinh(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::sinh(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::sinh(self) };
+    }
+
+    /// Hyperbolic cosine function.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let e = f64::consts::E;
+    /// let x = 1.0f64;
+    /// let f = x.cosh();
+    /// // Solving cosh() at 1 gives this result
+    /// let g = (e * e + 1.0) / (2.0 * e);
+    /// let abs_difference = (f - g).abs();
+    ///
+    /// // Same result
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn cosh(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::cosh(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::cosh(self) };
+    }
+
+    /// Hyperbolic tangent function.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let e = f64::consts::E;
+    /// let x = 1.0f64;
+    ///
+    /// let f = x.tanh();
+    /// // Solving tanh() at 1 gives `(1 - e^(-2))/(1 + e^(-2))`
+    /// let g = (1.0 - e.powi(-2)) / (1.0 + e.powi(-2));
+    /// let abs_difference = (f - g).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn tanh(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::tanh(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::tanh(self) };
+    }
+
+    /// Inverse hyperbolic tangent function.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let e = f64::consts::E;
+    /// let f = e.tanh().atanh();
+    ///
+    /// let abs_difference = (f - e).abs();
+    ///
+    /// assert!(abs_difference <= 1.0e-9);
+    /// ```
+    #[inline]
+    fn atanh(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::atanh(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::atanh(self) };
+    }
+
+    /// Calculates the length of the hypotenuse of a right-angle triangle
+    /// given legs of length `self` and `other`, avoiding the overflow and
+    /// underflow that the naive `(self * self + other * other).sqrt()` is
+    /// prone to for very large or very small inputs.
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = 2.0f64;
+    /// let y = 3.0f64;
+    ///
+    /// // sqrt(x^2 + y^2)
+    /// let abs_difference = (x.hypot(y) - (x.powi(2) + y.powi(2)).sqrt()).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn hypot(self, other: f64) -> f64 {
+        if self.is_infinite() || other.is_infinite() {
+            return ::core::f64::INFINITY;
+        }
+        if self.is_nan() || other.is_nan() {
+            return ::core::f64::NAN;
+        }
+        let a = self.abs();
+        let b = other.abs();
+        let hi = a.max(b);
+        let lo = a.min(b);
+        if hi == 0.0 {
+            0.0
+        } else {
+            hi * (1.0 + (lo / hi) * (lo / hi)).sqrt()
+        }
+    }
+
+    /// Returns the cube root of a number, preserving sign
+    /// (`x.cbrt() == -(-x).cbrt()`).
+    ///
+    /// ```
+    /// use std::f64;
+    ///
+    /// let x = 8.0f64;
+    ///
+    /// // x^(1/3) - 2 == 0
+    /// let abs_difference = (x.cbrt() - 2.0).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// assert_eq!((-x).cbrt(), -x.cbrt());
+    /// ```
+    #[inline]
+    fn cbrt(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::cbrt(self);
+        #[cfg(not(feature = "libm"))]
+        return unsafe { cmath::cbrt(self) };
+    }
+
+    /// Computes the error function of a number:
+    /// `erf(x) = (2/sqrt(pi)) * integral from 0 to x of e^(-t^2) dt`.
+    ///
+    /// ```
+    /// use std::f64;
+    /// use font_rs::float_impls::Float;
+    ///
+    /// // Qualified, since `f64` also has its own (unstable) inherent `erf`.
+    /// let abs_difference = (Float::erf(0.0f64) - 0.0).abs();
+    ///
+    /// assert!(abs_difference <= 1.5e-7);
+    /// ```
+    #[inline]
+    fn erf(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::erf(self);
+        #[cfg(not(feature = "libm"))]
+        {
+            // Abramowitz & Stegun 7.1.26, accurate to about 1.5e-7.
+            let sign = self.signum();
+            let x = self.abs();
+            let t = 1.0 / (1.0 + 0.3275911 * x);
+            let poly = (((((1.061405429 * t - 1.453152027) * t) + 1.421413741) * t
+                - 0.284496736) * t + 0.254829592) * t;
+            sign * (1.0 - poly * (-x * x).exp())
+        }
+    }
+
+    /// Computes the complementary error function of a number: `1 - erf(x)`.
+    ///
+    /// ```
+    /// use std::f64;
+    /// use font_rs::float_impls::Float;
+    ///
+    /// // Qualified, since `f64` also has its own (unstable) inherent `erfc`.
+    /// let abs_difference = (Float::erfc(0.0f64) - 1.0).abs();
+    ///
+    /// assert!(abs_difference <= 1.5e-7);
+    /// ```
+    #[inline]
+    fn erfc(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::erfc(self);
+        #[cfg(not(feature = "libm"))]
+        return 1.0 - self.erf();
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// ```
+    /// let x = 3.5_f64;
+    /// let y = -3.5_f64;
+    ///
+    /// assert_eq!(x.abs(), x);
+    /// assert_eq!(y.abs(), x);
+    /// ```
+    #[inline]
+    fn abs(self) -> f64 {
+        #[cfg(feature = "libm")]
+        return libm::fabs(self);
+        #[cfg(not(feature = "libm"))]
+        return f64::from_bits(self.to_bits() & 0x7fff_ffff_ffff_ffff);
+    }
+
+    /// Takes the reciprocal (inverse) of a number, `1/x`.
+    ///
+    /// ```
+    /// let x = 2.0_f64;
+    /// let abs_difference = (x.recip() - (1.0 / x)).abs();
+    ///
+    /// assert!(abs_difference <= f64::EPSILON);
+    /// ```
+    #[inline]
+    fn recip(self) -> f64 {
+        1.0 / self
+    }
+
+    /// Returns a number composed of the magnitude of `self` and the sign of
+    /// `sign`, works correctly for `+-0.0` and `NaN`.
+    ///
+    /// ```
+    /// let f = 3.5_f64;
+    ///
+    /// assert_eq!(f.copysign(0.42), 3.5);
+    /// assert_eq!(f.copysign(-0.42), -3.5);
+    /// assert_eq!((-f).copysign(0.42), 3.5);
+    /// assert_eq!((-f).copysign(-0.42), -3.5);
+    ///
+    /// assert!(f64::NAN.copysign(1.0).is_nan());
+    /// ```
+    #[inline]
+    fn copysign(self, sign: f64) -> f64 {
+        f64::from_bits((self.to_bits() & 0x7fff_ffff_ffff_ffff) | (sign.to_bits() & 0x8000_0000_0000_0000))
+    }
+
+    /// Restricts `self` to the interval `[min, max]`.
+    ///
+    /// Returns `min` if `self` is less than `min`, `max` if `self` is
+    /// greater than `max`, and otherwise returns `self`, propagating `NaN`.
+    ///
+    /// Panics (in debug builds) if `min > max`.
+    ///
+    /// ```
+    /// assert!((-3.0f64).clamp(-2.0, 1.0) == -2.0);
+    /// assert!((0.0f64).clamp(-2.0, 1.0) == 0.0);
+    /// assert!((2.0f64).clamp(-2.0, 1.0) == 1.0);
+    /// ```
+    #[inline]
+    fn clamp(self, min: f64, max: f64) -> f64 {
+        debug_assert!(min <= max);
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+}
+
+/// Fraction of a pixel covered by a straight edge at signed distance
+/// `signed_dist` from the pixel center, under a Gaussian blur of standard
+/// deviation `sigma`.
+///
+/// This gives a smoother, subpixel-stable antialiasing mode than box
+/// coverage: `0.5 * (1 + erf(d / (sigma * sqrt(2))))`.
+pub fn gaussian_edge_coverage(signed_dist: f32, sigma: f32) -> f32 {
+    // Qualified, since `f32` also has its own (unstable) inherent `erf`.
+    0.5 * (1.0 + Float::erf(signed_dist / (sigma * ::core::f32::consts::SQRT_2)))
+}
+
+/// Solves `a*t^3 + b*t^2 + c*t + d = 0` for `t` in `[0, 1]`, returning the
+/// in-range roots in ascending order.
+///
+/// Used by cubic Bézier flattening to find the parameter values at which a
+/// segment should be split (e.g. where its curvature or tangent direction
+/// crosses a threshold).
+///
+/// The cubic is first reduced to the depressed form `u^3 + p*u + q = 0` via
+/// the substitution `t = u - b/(3a)`, then solved with Cardano's formula when
+/// there is a single real root, or the trigonometric method when there are
+/// three. `a` must be non-zero (the equation must actually be cubic).
+///
+/// ```
+/// # use font_rs::float_impls::solve_cubic;
+/// // (t - 0.2)(t - 0.5)(t - 0.8) = t^3 - 1.5t^2 + 0.66t - 0.08
+/// let (roots, count) = solve_cubic(1.0, -1.5, 0.66, -0.08);
+/// assert_eq!(count, 3);
+/// assert!((roots[0] - 0.2).abs() < 1.0e-4);
+/// assert!((roots[1] - 0.5).abs() < 1.0e-4);
+/// assert!((roots[2] - 0.8).abs() < 1.0e-4);
+///
+/// // (t - 1)^3 = t^3 - 3t^2 + 3t - 1: a repeated root at the cusp.
+/// let (roots, count) = solve_cubic(1.0, -3.0, 3.0, -1.0);
+/// assert_eq!(count, 1);
+/// assert!((roots[0] - 1.0).abs() < 1.0e-4);
+/// ```
+pub fn solve_cubic(a: f32, b: f32, c: f32, d: f32) -> ([f32; 3], usize) {
+    debug_assert!(a != 0.0, "solve_cubic requires a non-zero leading coefficient");
+
+    let mut roots = [0.0f32; 3];
+    let mut count = 0;
+    let mut push = |t: f32| {
+        if (0.0..=1.0).contains(&t) && count < 3 {
+            roots[count] = t;
+            count += 1;
+        }
+    };
+
+    let offset = b / (3.0 * a);
+    let p = (3.0 * a * c - b * b) / (3.0 * a * a);
+    let q = (2.0 * b * b * b - 9.0 * a * b * c + 27.0 * a * a * d) / (27.0 * a * a * a);
+
+    let p3 = p / 3.0;
+    let q2 = q / 2.0;
+    let discriminant = q2 * q2 + p3 * p3 * p3;
+
+    if discriminant > 0.0 {
+        let sqrt_disc = discriminant.sqrt();
+        let u = (-q2 + sqrt_disc).cbrt();
+        let v = (-q2 - sqrt_disc).cbrt();
+        push(u + v - offset);
+    } else {
+        let r = (-p3).sqrt();
+        if r == 0.0 {
+            // Triple root (cusp): the depressed cubic is just u^3 = 0.
+            push(-offset);
+        } else {
+            let phi = (-q2 / (r * r * r)).acos();
+            for k in 0..3 {
+                let t = 2.0 * r * (((phi + 2.0 * ::core::f32::consts::PI * (k as f32)) / 3.0).cos()) - offset;
+                push(t);
+            }
+        }
+    }
+
+    roots[..count].sort_unstable_by(|x, y| x.partial_cmp(y).unwrap());
+    (roots, count)
 }